@@ -0,0 +1,560 @@
+use display_interface::{DataFormat, WriteOnlyDataCommand};
+use embedded_graphics_core::pixelcolor::{Rgb565, Rgb666};
+
+use crate::{error::Error, options::Orientation};
+
+/// Associates an embedded-graphics RGB color type with the bits-per-pixel value
+/// the display controller expects for it.
+pub trait PixelFormatColor {
+    /// Bits-per-pixel used to represent this color type on the wire.
+    const BITS_PER_PIXEL: BitsPerPixel;
+}
+
+impl PixelFormatColor for Rgb565 {
+    const BITS_PER_PIXEL: BitsPerPixel = BitsPerPixel::Sixteen;
+}
+
+impl PixelFormatColor for Rgb666 {
+    const BITS_PER_PIXEL: BitsPerPixel = BitsPerPixel::Eighteen;
+}
+
+/// A DCS (Display Command Set) command that can be written through a [Dcs] instance.
+pub trait DcsCommand {
+    /// The DCS instruction byte for this command.
+    fn instruction(&self) -> u8;
+
+    /// Fills `buffer` with this command's parameter bytes, returning how many were written.
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error>;
+}
+
+/// Wraps a [WriteOnlyDataCommand] display interface and writes DCS commands to it.
+pub struct Dcs<DI> {
+    /// The underlying display interface.
+    pub di: DI,
+}
+
+impl<DI> Dcs<DI> {
+    /// Creates a new `Dcs` wrapping the given display interface.
+    pub fn new(di: DI) -> Self {
+        Self { di }
+    }
+}
+
+impl<DI> Dcs<DI>
+where
+    DI: WriteOnlyDataCommand,
+{
+    /// Writes a DCS command, including its parameter bytes.
+    pub fn write_command(&mut self, command: impl DcsCommand) -> Result<(), Error> {
+        let mut param_bytes: [u8; 16] = [0; 16];
+        let n = command.fill_params_buf(&mut param_bytes)?;
+        self.write_raw(command.instruction(), &param_bytes[..n])
+    }
+
+    /// Writes a raw instruction byte followed by `param_bytes`.
+    pub fn write_raw(&mut self, instruction: u8, param_bytes: &[u8]) -> Result<(), Error> {
+        self.di.send_commands(DataFormat::U8(&[instruction]))?;
+        if !param_bytes.is_empty() {
+            self.di.send_data(DataFormat::U8(param_bytes))?;
+        }
+        Ok(())
+    }
+}
+
+/// Number of bits used to represent a single pixel's color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BitsPerPixel {
+    /// 16 bits per pixel (RGB565)
+    Sixteen,
+    /// 18 bits per pixel (RGB666)
+    Eighteen,
+}
+
+impl BitsPerPixel {
+    /// Picks the bits-per-pixel for a given embedded-graphics RGB color type.
+    pub fn from_rgb_color<C: PixelFormatColor>() -> Self {
+        C::BITS_PER_PIXEL
+    }
+
+    fn bits(self) -> u8 {
+        match self {
+            Self::Sixteen => 0b101,
+            Self::Eighteen => 0b110,
+        }
+    }
+}
+
+/// Pixel format sent to the display via [SetPixelFormat].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelFormat(u8);
+
+impl PixelFormat {
+    /// Builds a pixel format byte using the same bits-per-pixel for both the
+    /// DBI (MCU) and DPI (RGB interface) fields.
+    pub fn with_all(bpp: BitsPerPixel) -> Self {
+        let bits = bpp.bits();
+        Self(bits << 4 | bits)
+    }
+}
+
+/// `SOFT_RESET` (0x01): triggers a software reset of the display controller.
+pub struct SoftReset;
+
+impl DcsCommand for SoftReset {
+    fn instruction(&self) -> u8 {
+        0x01
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `EXIT_SLEEP_MODE` (0x11): wakes the display controller from sleep.
+pub struct ExitSleepMode;
+
+impl DcsCommand for ExitSleepMode {
+    fn instruction(&self) -> u8 {
+        0x11
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `ENTER_IDLE_MODE` (0x39): drops the display to its 8-color reduced palette for low power use.
+pub struct EnterIdleMode;
+
+impl DcsCommand for EnterIdleMode {
+    fn instruction(&self) -> u8 {
+        0x39
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `EXIT_IDLE_MODE` (0x38): leaves idle mode, restoring the full color palette.
+pub struct ExitIdleMode;
+
+impl DcsCommand for ExitIdleMode {
+    fn instruction(&self) -> u8 {
+        0x38
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `ENTER_NORMAL_MODE` (0x13): leaves partial mode and enters normal display mode.
+pub struct EnterNormalMode;
+
+impl DcsCommand for EnterNormalMode {
+    fn instruction(&self) -> u8 {
+        0x13
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `SET_DISPLAY_ON` (0x29): turns the display output on.
+pub struct SetDisplayOn;
+
+impl DcsCommand for SetDisplayOn {
+    fn instruction(&self) -> u8 {
+        0x29
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `ENTER/EXIT_INVERT_MODE` (0x21/0x20): inverts the displayed colors.
+pub struct SetInvertMode {
+    invert: bool,
+}
+
+impl SetInvertMode {
+    /// Creates a new `SetInvertMode` command.
+    pub fn new(invert: bool) -> Self {
+        Self { invert }
+    }
+}
+
+impl DcsCommand for SetInvertMode {
+    fn instruction(&self) -> u8 {
+        if self.invert {
+            0x21
+        } else {
+            0x20
+        }
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `SET_PIXEL_FORMAT` (0x3A): sets the pixel format used for subsequent memory writes.
+pub struct SetPixelFormat {
+    format: PixelFormat,
+}
+
+impl SetPixelFormat {
+    /// Creates a new `SetPixelFormat` command.
+    pub fn new(format: PixelFormat) -> Self {
+        Self { format }
+    }
+}
+
+impl DcsCommand for SetPixelFormat {
+    fn instruction(&self) -> u8 {
+        0x3a
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0] = self.format.0;
+        Ok(1)
+    }
+}
+
+/// `SET_ADDRESS_MODE` (0x36): sets the MADCTL memory access / orientation bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SetAddressMode(u8);
+
+impl SetAddressMode {
+    /// Creates a new `SetAddressMode` command from the given [Orientation].
+    pub fn new(orientation: Orientation) -> Self {
+        let value = match orientation {
+            Orientation::Portrait(mirrored) => mirror_bit(mirrored),
+            Orientation::Landscape(mirrored) => 0b010_0000 ^ mirror_bit(mirrored),
+            Orientation::PortraitInverted(mirrored) => 0b110_0000 ^ mirror_bit(mirrored),
+            Orientation::LandscapeInverted(mirrored) => 0b100_0000 ^ mirror_bit(mirrored),
+        };
+        Self(value)
+    }
+}
+
+fn mirror_bit(mirrored: bool) -> u8 {
+    if mirrored {
+        0b1000_0000
+    } else {
+        0
+    }
+}
+
+impl DcsCommand for SetAddressMode {
+    fn instruction(&self) -> u8 {
+        0x36
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0] = self.0;
+        Ok(1)
+    }
+}
+
+/// `WRITE_MEMORY_START` (0x2C): begins a pixel data write into the previously set address window.
+pub struct WriteMemoryStart;
+
+impl DcsCommand for WriteMemoryStart {
+    fn instruction(&self) -> u8 {
+        0x2c
+    }
+
+    fn fill_params_buf(&self, _buffer: &mut [u8]) -> Result<usize, Error> {
+        Ok(0)
+    }
+}
+
+/// `SET_COLUMN_ADDRESS` (0x2A): sets the start/end column of the address window.
+pub struct SetColumnAddress {
+    sx: u16,
+    ex: u16,
+}
+
+impl SetColumnAddress {
+    /// Creates a new `SetColumnAddress` command.
+    pub fn new(sx: u16, ex: u16) -> Self {
+        Self { sx, ex }
+    }
+}
+
+impl DcsCommand for SetColumnAddress {
+    fn instruction(&self) -> u8 {
+        0x2a
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0..4].copy_from_slice(&[
+            (self.sx >> 8) as u8,
+            self.sx as u8,
+            (self.ex >> 8) as u8,
+            self.ex as u8,
+        ]);
+        Ok(4)
+    }
+}
+
+/// `WRITE_DISPLAY_BRIGHTNESS` (0x51): sets the display's backlight brightness (DBV).
+pub struct WriteDisplayBrightness {
+    brightness: u8,
+}
+
+impl WriteDisplayBrightness {
+    /// Creates a new `WriteDisplayBrightness` command.
+    pub fn new(brightness: u8) -> Self {
+        Self { brightness }
+    }
+}
+
+impl DcsCommand for WriteDisplayBrightness {
+    fn instruction(&self) -> u8 {
+        0x51
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0] = self.brightness;
+        Ok(1)
+    }
+}
+
+/// Backlight control block bits used by [WriteCTRLDisplay].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CTRLDisplay {
+    /// Backlight control on/off.
+    pub backlight_on: bool,
+    /// Display dimming on/off.
+    pub dimming_on: bool,
+    /// Brightness control block on/off (required for [WriteDisplayBrightness] to take effect).
+    pub brightness_control_on: bool,
+}
+
+/// `WRITE_CTRL_DISPLAY` (0x53): enables/disables the backlight, dimming and brightness control.
+pub struct WriteCTRLDisplay(CTRLDisplay);
+
+impl WriteCTRLDisplay {
+    /// Creates a new `WriteCTRLDisplay` command.
+    pub fn new(ctrl: CTRLDisplay) -> Self {
+        Self(ctrl)
+    }
+}
+
+impl DcsCommand for WriteCTRLDisplay {
+    fn instruction(&self) -> u8 {
+        0x53
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        let mut value = 0u8;
+        if self.0.backlight_on {
+            value |= 1 << 2;
+        }
+        if self.0.dimming_on {
+            value |= 1 << 3;
+        }
+        if self.0.brightness_control_on {
+            value |= 1 << 5;
+        }
+        buffer[0] = value;
+        Ok(1)
+    }
+}
+
+/// `WRITE_CABC` (0x55): sets the Content Adaptive Brightness Control mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cabc {
+    /// Adaptive brightness control is disabled.
+    Off,
+    /// Adaptive brightness control tuned for still images (e.g. photos).
+    StillPicture,
+    /// Adaptive brightness control tuned for moving images (e.g. video).
+    MovingImage,
+    /// Adaptive brightness control tuned for user-interface images.
+    UserInterface,
+}
+
+/// `WRITE_CABC` (0x55): sets the Content Adaptive Brightness Control mode.
+pub struct WriteCABC(Cabc);
+
+impl WriteCABC {
+    /// Creates a new `WriteCABC` command.
+    pub fn new(mode: Cabc) -> Self {
+        Self(mode)
+    }
+}
+
+impl DcsCommand for WriteCABC {
+    fn instruction(&self) -> u8 {
+        0x55
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0] = match self.0 {
+            Cabc::Off => 0x00,
+            Cabc::UserInterface => 0x01,
+            Cabc::StillPicture => 0x02,
+            Cabc::MovingImage => 0x03,
+        };
+        Ok(1)
+    }
+}
+
+/// `SET_PAGE_ADDRESS` (0x2B): sets the start/end row of the address window.
+pub struct SetPageAddress {
+    sy: u16,
+    ey: u16,
+}
+
+impl SetPageAddress {
+    /// Creates a new `SetPageAddress` command.
+    pub fn new(sy: u16, ey: u16) -> Self {
+        Self { sy, ey }
+    }
+}
+
+impl DcsCommand for SetPageAddress {
+    fn instruction(&self) -> u8 {
+        0x2b
+    }
+
+    fn fill_params_buf(&self, buffer: &mut [u8]) -> Result<usize, Error> {
+        buffer[0..4].copy_from_slice(&[
+            (self.sy >> 8) as u8,
+            self.sy as u8,
+            (self.ey >> 8) as u8,
+            self.ey as u8,
+        ]);
+        Ok(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Cabc, CTRLDisplay, DcsCommand, EnterIdleMode, ExitIdleMode, SetAddressMode, WriteCABC,
+        WriteCTRLDisplay, WriteDisplayBrightness,
+    };
+    use crate::options::Orientation;
+
+    #[test]
+    fn idle_mode_commands_use_distinct_instructions_and_no_params() {
+        let mut buf = [0u8; 16];
+
+        assert_eq!(EnterIdleMode.instruction(), 0x39);
+        assert_eq!(EnterIdleMode.fill_params_buf(&mut buf).unwrap(), 0);
+
+        assert_eq!(ExitIdleMode.instruction(), 0x38);
+        assert_eq!(ExitIdleMode.fill_params_buf(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn write_display_brightness_sends_the_raw_dbv_value() {
+        let mut buf = [0u8; 16];
+        let n = WriteDisplayBrightness::new(0x7f)
+            .fill_params_buf(&mut buf)
+            .unwrap();
+        assert_eq!(buf[..n], [0x7f]);
+    }
+
+    #[test]
+    fn write_ctrl_display_packs_flags_into_bits_2_3_5() {
+        let mut buf = [0u8; 16];
+        let n = WriteCTRLDisplay::new(CTRLDisplay {
+            backlight_on: true,
+            dimming_on: false,
+            brightness_control_on: true,
+        })
+        .fill_params_buf(&mut buf)
+        .unwrap();
+        assert_eq!(buf[..n], [0b0010_0100]);
+
+        let n = WriteCTRLDisplay::new(CTRLDisplay {
+            backlight_on: false,
+            dimming_on: true,
+            brightness_control_on: false,
+        })
+        .fill_params_buf(&mut buf)
+        .unwrap();
+        assert_eq!(buf[..n], [0b0000_1000]);
+    }
+
+    #[test]
+    fn write_cabc_maps_each_mode_to_its_wire_value() {
+        let mut buf = [0u8; 16];
+        for (mode, expected) in [
+            (Cabc::Off, 0x00),
+            (Cabc::UserInterface, 0x01),
+            (Cabc::StillPicture, 0x02),
+            (Cabc::MovingImage, 0x03),
+        ] {
+            let n = WriteCABC::new(mode).fill_params_buf(&mut buf).unwrap();
+            assert_eq!(buf[..n], [expected]);
+        }
+    }
+
+    #[test]
+    fn set_address_mode_orientations_are_distinct() {
+        let orientations = [
+            Orientation::Portrait(false),
+            Orientation::Portrait(true),
+            Orientation::Landscape(false),
+            Orientation::Landscape(true),
+            Orientation::PortraitInverted(false),
+            Orientation::PortraitInverted(true),
+            Orientation::LandscapeInverted(false),
+            Orientation::LandscapeInverted(true),
+        ];
+
+        let bytes = orientations.map(|o| SetAddressMode::new(o).0);
+
+        for i in 0..bytes.len() {
+            for j in (i + 1)..bytes.len() {
+                assert_ne!(bytes[i], bytes[j], "orientations {i} and {j} collide");
+            }
+        }
+    }
+
+    #[test]
+    fn mirror_bit_toggles_independently_of_rotation_bits() {
+        // The mirror flag (bit 7, the MY bit) must only ever flip that one bit,
+        // leaving the rotation encoding (bits 5-6) untouched, for every rotation.
+        // If a future rotation value is ever assigned bit 7 this test catches the
+        // collision before mirroring silently becomes a no-op again.
+        const ROTATION_MASK: u8 = 0b0110_0000;
+        const MIRROR_BIT: u8 = 0b1000_0000;
+
+        for (plain, mirrored) in [
+            (Orientation::Portrait(false), Orientation::Portrait(true)),
+            (Orientation::Landscape(false), Orientation::Landscape(true)),
+            (
+                Orientation::PortraitInverted(false),
+                Orientation::PortraitInverted(true),
+            ),
+            (
+                Orientation::LandscapeInverted(false),
+                Orientation::LandscapeInverted(true),
+            ),
+        ] {
+            let plain_byte = SetAddressMode::new(plain).0;
+            let mirrored_byte = SetAddressMode::new(mirrored).0;
+
+            assert_eq!(
+                plain_byte & ROTATION_MASK,
+                mirrored_byte & ROTATION_MASK,
+                "mirroring changed the rotation bits"
+            );
+            assert_eq!(
+                plain_byte | MIRROR_BIT,
+                mirrored_byte,
+                "mirroring didn't set exactly the MY bit"
+            );
+        }
+    }
+}
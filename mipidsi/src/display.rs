@@ -0,0 +1,142 @@
+use display_interface::WriteOnlyDataCommand;
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+
+use crate::{
+    dcs::{
+        BitsPerPixel, Cabc, CTRLDisplay, Dcs, EnterIdleMode, ExitIdleMode, PixelFormat,
+        PixelFormatColor, SetAddressMode, SetColumnAddress, SetPageAddress, SetPixelFormat,
+        WriteCABC, WriteCTRLDisplay, WriteDisplayBrightness,
+    },
+    error::{Error, InitError},
+    models::Model,
+    options::{ModelOptions, Orientation},
+};
+
+/// Driver for a display controller, combining a [Model] with its display interface,
+/// optional reset pin and [ModelOptions].
+pub struct Display<DI, MODEL, RST> {
+    dcs: Dcs<DI>,
+    model: MODEL,
+    rst: Option<RST>,
+    options: ModelOptions,
+    madctl: SetAddressMode,
+}
+
+impl<DI, MODEL, RST> Display<DI, MODEL, RST>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    RST: OutputPin,
+{
+    /// Creates a new `Display`, running the model's init sequence.
+    pub fn init<DELAY>(
+        di: DI,
+        mut model: MODEL,
+        options: ModelOptions,
+        delay: &mut DELAY,
+        mut rst: Option<RST>,
+    ) -> Result<Self, InitError<RST::Error>>
+    where
+        DELAY: DelayNs,
+    {
+        let mut dcs = Dcs::new(di);
+        let madctl = model.init(&mut dcs, delay, &options, &mut rst)?;
+
+        Ok(Self {
+            dcs,
+            model,
+            rst,
+            options,
+            madctl,
+        })
+    }
+
+    /// Sets the display's backlight brightness (DBV), `0..=255`.
+    ///
+    /// Many ILI9488/ILI9341 panels wire DBV through the controller rather than a
+    /// hardware PWM pin, so this is the only way to dim those displays.
+    pub fn set_brightness(&mut self, brightness: u8) -> Result<(), Error> {
+        self.dcs.write_command(WriteCTRLDisplay::new(CTRLDisplay {
+            backlight_on: true,
+            dimming_on: false,
+            brightness_control_on: true,
+        }))?;
+        self.dcs
+            .write_command(WriteDisplayBrightness::new(brightness))
+    }
+
+    /// Enables or configures Content Adaptive Brightness Control.
+    pub fn set_adaptive_brightness(&mut self, mode: Cabc) -> Result<(), Error> {
+        self.dcs.write_command(WriteCABC::new(mode))
+    }
+
+    /// Returns the orientation the display was configured with.
+    pub fn orientation(&self) -> Orientation {
+        self.options.orientation
+    }
+
+    /// Returns the [SetAddressMode] (MADCTL) applied during [Self::init].
+    pub fn address_mode(&self) -> SetAddressMode {
+        self.madctl
+    }
+
+    /// Re-runs a hardware reset through the `RST` pin, if one was provided to [Self::init].
+    pub fn hard_reset<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), InitError<RST::Error>>
+    where
+        DELAY: DelayNs,
+    {
+        match &mut self.rst {
+            Some(rst) => self.model.hard_reset(rst, delay),
+            None => Ok(()),
+        }
+    }
+
+    /// Fills the rectangle `(sx, sy)..=(ex, ey)` (in pixels, inclusive) with a solid color.
+    ///
+    /// This sets the address window once and delegates to [Model::fill_contiguous],
+    /// which is far cheaper than drawing the rectangle pixel by pixel for large fills.
+    pub fn fill_solid(
+        &mut self,
+        sx: u16,
+        sy: u16,
+        ex: u16,
+        ey: u16,
+        color: MODEL::ColorFormat,
+    ) -> Result<(), Error> {
+        let (fb_width, fb_height) = MODEL::FRAMEBUFFER_SIZE;
+        if sx > ex || sy > ey || ex >= fb_width || ey >= fb_height {
+            return Err(Error::OutOfBounds);
+        }
+
+        self.dcs.write_command(SetColumnAddress::new(sx, ex))?;
+        self.dcs.write_command(SetPageAddress::new(sy, ey))?;
+
+        let count = (ex as usize - sx as usize + 1) * (ey as usize - sy as usize + 1);
+        self.model.fill_contiguous(&mut self.dcs, color, count)
+    }
+}
+
+impl<DI, MODEL, RST> Display<DI, MODEL, RST>
+where
+    DI: WriteOnlyDataCommand,
+    MODEL: Model,
+    MODEL::ColorFormat: PixelFormatColor,
+    RST: OutputPin,
+{
+    /// Enters or exits the controller's idle mode.
+    ///
+    /// Idle mode drops the panel to an 8-color reduced palette at much lower
+    /// power, which is useful for battery-powered always-on status screens.
+    /// Leaving idle mode re-issues the model's normal pixel format, since some
+    /// controllers reset it on entering idle mode.
+    pub fn set_idle_mode(&mut self, idle: bool) -> Result<(), Error> {
+        if idle {
+            self.dcs.write_command(EnterIdleMode)
+        } else {
+            self.dcs.write_command(ExitIdleMode)?;
+            let bpp = BitsPerPixel::from_rgb_color::<MODEL::ColorFormat>();
+            self.dcs
+                .write_command(SetPixelFormat::new(PixelFormat::with_all(bpp)))
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use display_interface::DisplayError;
+
+/// Error returned by operations on the display
+#[derive(Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Error caused by the display interface
+    DisplayError,
+    /// A rectangle's end coordinate was smaller than its start coordinate, or lay
+    /// outside the model's framebuffer
+    OutOfBounds,
+}
+
+impl From<DisplayError> for Error {
+    fn from(_: DisplayError) -> Self {
+        Self::DisplayError
+    }
+}
+
+/// Error returned from the [`init`](crate::models::Model::init) methods: either a display
+/// error (every [Error] variant converts to [InitError::DisplayError]) or a GPIO pin error.
+#[derive(Debug)]
+pub enum InitError<PE> {
+    /// Error caused by the display interface
+    DisplayError,
+    /// Error caused by the reset pin
+    Pin(PE),
+}
+
+impl<PE> From<Error> for InitError<PE> {
+    fn from(_: Error) -> Self {
+        Self::DisplayError
+    }
+}
+
+impl<PE> From<DisplayError> for InitError<PE> {
+    fn from(_: DisplayError) -> Self {
+        Self::DisplayError
+    }
+}
@@ -0,0 +1,12 @@
+//! MIPI DSI / DBI display driver.
+#![no_std]
+
+pub mod dcs;
+mod display;
+pub mod error;
+pub mod models;
+pub mod options;
+
+pub use display::Display;
+pub use error::Error;
+pub use options::{ModelOptions, Orientation};
@@ -1,5 +1,8 @@
 use display_interface::{DataFormat, WriteOnlyDataCommand};
-use embedded_graphics_core::{pixelcolor::Rgb565, prelude::IntoStorage};
+use embedded_graphics_core::{
+    pixelcolor::{Rgb565, Rgb666},
+    prelude::{IntoStorage, RgbColor},
+};
 use embedded_hal::{delay::DelayNs, digital::OutputPin};
 
 use crate::{
@@ -50,10 +53,124 @@ impl Model for ILI9488Rgb565 {
         let mut iter = colors.into_iter().map(|c| c.into_storage());
 
         let buf = DataFormat::U16BEIter(&mut iter);
-        dcs.di.send_data(buf)
+        Ok(dcs.di.send_data(buf)?)
+    }
+
+    fn fill_contiguous<DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        color: Self::ColorFormat,
+        count: usize,
+    ) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+    {
+        const CHUNK_PIXELS: usize = 32;
+
+        dcs.write_command(WriteMemoryStart)?;
+
+        let raw = color.into_storage().to_be_bytes();
+        let mut buf = [0u8; CHUNK_PIXELS * 2];
+        for pixel in buf.chunks_exact_mut(2) {
+            pixel.copy_from_slice(&raw);
+        }
+
+        let mut remaining = count;
+        while remaining > 0 {
+            let n = remaining.min(CHUNK_PIXELS);
+            dcs.di.send_data(DataFormat::U8(&buf[..n * 2]))?;
+            remaining -= n;
+        }
+        Ok(())
     }
 }
 
+/// ILI9488 display in Rgb666 color mode.
+///
+/// The ILI9488 cannot accept 16 bits-per-pixel data over a 3/4-wire SPI
+/// interface; the panel only understands 18bpp (RGB666) pixel data in that
+/// mode, so this model packs each color into three bytes instead of two.
+pub struct ILI9488Rgb666;
+
+impl Model for ILI9488Rgb666 {
+    type ColorFormat = Rgb666;
+    const FRAMEBUFFER_SIZE: (u16, u16) = (320, 480);
+
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: WriteOnlyDataCommand,
+    {
+        match rst {
+            Some(ref mut rst) => self.hard_reset(rst, delay)?,
+            None => dcs.write_command(SoftReset)?,
+        }
+        delay.delay_us(120_000);
+
+        let pf = PixelFormat::with_all(BitsPerPixel::Eighteen);
+        Ok(init_common(dcs, delay, options, pf)?)
+    }
+
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>,
+    {
+        dcs.write_command(WriteMemoryStart)?;
+
+        let mut iter = colors.into_iter().flat_map(|c| {
+            [c.r() << 2, c.g() << 2, c.b() << 2]
+        });
+
+        let buf = DataFormat::U8Iter(&mut iter);
+        Ok(dcs.di.send_data(buf)?)
+    }
+
+    fn fill_contiguous<DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        color: Self::ColorFormat,
+        count: usize,
+    ) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+    {
+        const CHUNK_PIXELS: usize = 32;
+
+        dcs.write_command(WriteMemoryStart)?;
+
+        let raw = [color.r() << 2, color.g() << 2, color.b() << 2];
+        let mut buf = [0u8; CHUNK_PIXELS * 3];
+        for pixel in buf.chunks_exact_mut(3) {
+            pixel.copy_from_slice(&raw);
+        }
+
+        let mut remaining = count;
+        while remaining > 0 {
+            let n = remaining.min(CHUNK_PIXELS);
+            dcs.di.send_data(DataFormat::U8(&buf[..n * 3]))?;
+            remaining -= n;
+        }
+        Ok(())
+    }
+}
+
+const DEFAULT_VCOM: [u8; 4] = [0x00, 0x1e, 0x80, 0xb1];
+const DEFAULT_FRAME_RATE: u8 = 0xb0;
+const DEFAULT_GAMMA_POSITIVE: [u8; 15] = [
+    0x0, 0x13, 0x18, 0x04, 0x0F, 0x06, 0x3a, 0x56, 0x4d, 0x03, 0x0a, 0x06, 0x30, 0x3e, 0x0f,
+];
+const DEFAULT_GAMMA_NEGATIVE: [u8; 15] = [
+    0x0, 0x13, 0x18, 0x01, 0x11, 0x06, 0x38, 0x34, 0x4d, 0x06, 0x0d, 0x0b, 0x31, 0x37, 0x0f,
+];
+
 // common init for all color format models
 fn init_common<DELAY, DI>(
     dcs: &mut Dcs<DI>,
@@ -68,33 +185,16 @@ where
     let madctl = SetAddressMode::from(options);
     dcs.write_command(ExitSleepMode)?; // turn off sleep
     dcs.write_command(SetPixelFormat::new(pixel_format))?; // pixel format
-    dcs.write_command(madctl)?; // left -> right, bottom -> top RGB
+    dcs.write_command(madctl)?; // memory access control, derived from the chosen orientation
     dcs.write_command(SetInvertMode::new(options.invert_colors))?;
-    dcs.write_raw(0xc5, &[0x00, 0x1e, 0x80, 0xb1])?; // vcom control
-    dcs.write_raw(0xb1, &[0xb0])?; // frame rate
-
-    // optional gamma setup
-    dcs.write_raw(
-        0xe0,
-        &[
-            0x0, 0x13, 0x18, 0x04, 0x0F, 0x06, 0x3a, 0x56, 0x4d, 0x03, 0x0a, 0x06, 0x30, 0x3e, 0x0f,
-        ],
-    )?;
-    dcs.write_raw(
-        0xe1,
-        &[
-            0x0, 0x13, 0x18, 0x01, 0x11, 0x06, 0x38, 0x34, 0x4d, 0x06, 0x0d, 0x0b, 0x31, 0x37, 0x0f,
-        ],
-    )?;
+    dcs.write_raw(0xc5, &options.vcom.unwrap_or(DEFAULT_VCOM))?; // vcom control
+    dcs.write_raw(0xb1, &[options.frame_rate.unwrap_or(DEFAULT_FRAME_RATE)])?; // frame rate
 
-    // dcs.write_raw(0x3a, &[0x55])?; // set 16-bit pixel display
+    // gamma setup, calibrated per-panel via `ModelOptions` if provided
+    dcs.write_raw(0xe0, &options.gamma_positive.unwrap_or(DEFAULT_GAMMA_POSITIVE))?;
+    dcs.write_raw(0xe1, &options.gamma_negative.unwrap_or(DEFAULT_GAMMA_NEGATIVE))?;
 
-    // NOTE: manually setting memory access data control, ignoring passed in
-    let _l2r_u2d = 0x22; // blank
-    let _d2u_l2r = 0x62; // blank
-    let r2l_d2u = 0x42; // worked
-    let u2d_r2l = 0x02; // looked same
-    dcs.write_raw(0xB6, &[0b0000_0000, r2l_d2u])?; // L2R_U2D
+    // dcs.write_raw(0x3a, &[0x55])?; // set 16-bit pixel display
 
     dcs.write_command(EnterNormalMode)?; // turn to normal mode
     dcs.write_command(SetDisplayOn)?; // turn on display
@@ -107,13 +207,17 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::dcs::{self, DcsCommand};
+    use display_interface::{DataFormat, DisplayError, WriteOnlyDataCommand};
+    use embedded_graphics_core::pixelcolor::Rgb666;
+
+    use crate::dcs::{self, Dcs, DcsCommand};
+
+    use super::{ILI9488Rgb666, Model};
 
     #[test]
     fn cm_struct() {
         let cm1 = [0x2a, 0x00, 0x00, 0x00, 0x05];
         let cm2 = [0x2b, 0x00, 0x00, 0x00, 0x05];
-        let cm3 = [0x2c, 0xff, 0xff, 0xff, 0xff];
         let sx = 0;
         let sy = 0;
         let ex = 5;
@@ -121,10 +225,85 @@ mod tests {
         let res1 = dcs::SetColumnAddress::new(sx, ex);
         let res2 = dcs::SetPageAddress::new(sy, ey);
         let mut param_bytes: [u8; 16] = [0; 16];
+
         let n = res1.fill_params_buf(&mut param_bytes).unwrap();
-        let ins = res1.instruction();
+        assert_eq!(cm1[0], res1.instruction());
+        assert_eq!(cm1[1..1 + n], param_bytes[0..n]);
 
-        assert_eq!(cm1[0], ins);
-        assert_eq!(cm1[1..4], param_bytes[0..3]);
+        let n = res2.fill_params_buf(&mut param_bytes).unwrap();
+        assert_eq!(cm2[0], res2.instruction());
+        assert_eq!(cm2[1..1 + n], param_bytes[0..n]);
+    }
+
+    /// Records the data bytes sent through the [display_interface::WriteOnlyDataCommand]
+    /// interface so tests can assert on the exact pixel bytes a model produces.
+    /// Command bytes are discarded.
+    struct RecordingDi {
+        data: [u8; 256],
+        data_len: usize,
+    }
+
+    impl RecordingDi {
+        fn new() -> Self {
+            Self {
+                data: [0; 256],
+                data_len: 0,
+            }
+        }
+
+        fn push(&mut self, bytes: &[u8]) {
+            self.data[self.data_len..self.data_len + bytes.len()].copy_from_slice(bytes);
+            self.data_len += bytes.len();
+        }
+    }
+
+    impl WriteOnlyDataCommand for RecordingDi {
+        fn send_commands(&mut self, _cmd: DataFormat<'_>) -> Result<(), DisplayError> {
+            Ok(())
+        }
+
+        fn send_data(&mut self, buf: DataFormat<'_>) -> Result<(), DisplayError> {
+            match buf {
+                DataFormat::U8(bytes) => self.push(bytes),
+                DataFormat::U8Iter(iter) => {
+                    for b in iter {
+                        self.push(&[b]);
+                    }
+                }
+                DataFormat::U16BEIter(iter) => {
+                    for v in iter {
+                        self.push(&v.to_be_bytes());
+                    }
+                }
+                _ => unreachable!("not used by these models"),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn rgb666_write_pixels_packs_top_six_bits_into_three_bytes() {
+        let mut dcs = Dcs::new(RecordingDi::new());
+        let color = Rgb666::new(1, 2, 3);
+
+        ILI9488Rgb666.write_pixels(&mut dcs, [color]).unwrap();
+
+        assert_eq!(dcs.di.data[..dcs.di.data_len], [4, 8, 12]);
+    }
+
+    #[test]
+    fn fill_contiguous_handles_counts_that_are_not_a_multiple_of_the_chunk_size() {
+        let mut dcs = Dcs::new(RecordingDi::new());
+        let color = Rgb666::new(1, 2, 3);
+        let count = 35; // one full 32-pixel chunk plus a 3-pixel remainder
+
+        ILI9488Rgb666
+            .fill_contiguous(&mut dcs, color, count)
+            .unwrap();
+
+        assert_eq!(dcs.di.data_len, count * 3);
+        for pixel in dcs.di.data[..dcs.di.data_len].chunks_exact(3) {
+            assert_eq!(pixel, [4, 8, 12]);
+        }
     }
 }
@@ -0,0 +1,78 @@
+use display_interface::WriteOnlyDataCommand;
+use embedded_graphics_core::pixelcolor::PixelColor;
+use embedded_hal::{delay::DelayNs, digital::OutputPin};
+
+use crate::{
+    dcs::{Dcs, SetAddressMode},
+    error::{Error, InitError},
+    options::ModelOptions,
+};
+
+mod ili9488;
+
+pub use ili9488::{ILI9488Rgb565, ILI9488Rgb666};
+
+/// A display controller's model-specific behavior: its reset/init sequence and
+/// how it expects pixel data to be written to it.
+pub trait Model {
+    /// The color format accepted by [Self::write_pixels].
+    type ColorFormat: PixelColor;
+
+    /// Number of pixels supported by this model, in the `(width, height)` orientation
+    /// the controller comes up in.
+    const FRAMEBUFFER_SIZE: (u16, u16);
+
+    /// Runs this model's power-on init sequence, returning the [SetAddressMode]
+    /// that was applied.
+    fn init<RST, DELAY, DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        delay: &mut DELAY,
+        options: &ModelOptions,
+        rst: &mut Option<RST>,
+    ) -> Result<SetAddressMode, InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+        DI: WriteOnlyDataCommand;
+
+    /// Writes `colors` into the address window previously set on the controller.
+    fn write_pixels<DI, I>(&mut self, dcs: &mut Dcs<DI>, colors: I) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+        I: IntoIterator<Item = Self::ColorFormat>;
+
+    /// Fills the currently-set address window with `count` repetitions of `color`.
+    ///
+    /// The default implementation simply repeats `color` through [Self::write_pixels].
+    /// Models should override this to batch identical pixels into a small reusable
+    /// buffer instead of materializing an iterator that yields each pixel
+    /// individually, which cuts SPI setup cost for large clears and solid fills.
+    fn fill_contiguous<DI>(
+        &mut self,
+        dcs: &mut Dcs<DI>,
+        color: Self::ColorFormat,
+        count: usize,
+    ) -> Result<(), Error>
+    where
+        DI: WriteOnlyDataCommand,
+    {
+        self.write_pixels(dcs, core::iter::repeat_n(color, count))
+    }
+
+    /// Performs a hardware reset using the `RST` pin.
+    fn hard_reset<RST, DELAY>(
+        &mut self,
+        rst: &mut RST,
+        delay: &mut DELAY,
+    ) -> Result<(), InitError<RST::Error>>
+    where
+        RST: OutputPin,
+        DELAY: DelayNs,
+    {
+        rst.set_low().map_err(InitError::Pin)?;
+        delay.delay_us(10);
+        rst.set_high().map_err(InitError::Pin)?;
+        Ok(())
+    }
+}
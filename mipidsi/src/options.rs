@@ -0,0 +1,43 @@
+use crate::dcs::SetAddressMode;
+
+/// Display orientation, defining how the framebuffer maps onto the physical panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Portrait orientation, with origin at the top left.
+    Portrait(bool),
+    /// Landscape orientation, with origin at the top left.
+    Landscape(bool),
+    /// Portrait orientation, inverted (origin at the bottom right).
+    PortraitInverted(bool),
+    /// Landscape orientation, inverted (origin at the bottom right).
+    LandscapeInverted(bool),
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Self::Portrait(false)
+    }
+}
+
+/// Options that control how a [`Model`](crate::models::Model) is initialized.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ModelOptions {
+    /// Display orientation
+    pub orientation: Orientation,
+    /// Whether the display controller should invert the displayed colors
+    pub invert_colors: bool,
+    /// Positive gamma correction table (`0xE0`), overriding the model's built-in default.
+    pub gamma_positive: Option<[u8; 15]>,
+    /// Negative gamma correction table (`0xE1`), overriding the model's built-in default.
+    pub gamma_negative: Option<[u8; 15]>,
+    /// VCOM control parameters (`0xC5`), overriding the model's built-in default.
+    pub vcom: Option<[u8; 4]>,
+    /// Frame rate control parameter (`0xB1`), overriding the model's built-in default.
+    pub frame_rate: Option<u8>,
+}
+
+impl From<&ModelOptions> for SetAddressMode {
+    fn from(options: &ModelOptions) -> Self {
+        SetAddressMode::new(options.orientation)
+    }
+}